@@ -26,22 +26,22 @@ fn run_std() {
         let tx4 = tx.clone();
         scope.spawn(move || {
             for item in generate_data(1) {
-                tx.send(item);
+                tx.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx2.send(item);
+                tx2.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx3.send(item);
+                tx3.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx4.send(item);
+                tx4.send(item).unwrap();
             }
         });
         scope.spawn(move || {
@@ -64,22 +64,22 @@ fn run_crossbeam() {
         let tx4 = tx.clone();
         scope.spawn(move || {
             for item in generate_data(1) {
-                tx.send(item);
+                tx.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx2.send(item);
+                tx2.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx3.send(item);
+                tx3.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx4.send(item);
+                tx4.send(item).unwrap();
             }
         });
         scope.spawn(move || {
@@ -102,22 +102,22 @@ fn run_custom_one() {
         let tx4 = tx.clone();
         scope.spawn(move || {
             for item in generate_data(1) {
-                tx.send(item);
+                tx.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx2.send(item);
+                tx2.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(3) {
-                tx3.send(item);
+                tx3.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(4) {
-                tx4.send(item);
+                tx4.send(item).unwrap();
             }
         });
         scope.spawn(move || {
@@ -141,22 +141,22 @@ fn run_custom_exact(num: usize) {
         let tx4 = tx.clone();
         scope.spawn(move || {
             for item in generate_data(1) {
-                tx.send(item);
+                tx.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx2.send(item);
+                tx2.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx3.send(item);
+                tx3.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx4.send(item);
+                tx4.send(item).unwrap();
             }
         });
         scope.spawn(move || {
@@ -179,22 +179,22 @@ fn run_custom_all() {
         let tx4 = tx.clone();
         scope.spawn(move || {
             for item in generate_data(1) {
-                tx.send(item);
+                tx.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx2.send(item);
+                tx2.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx3.send(item);
+                tx3.send(item).unwrap();
             }
         });
         scope.spawn(move || {
             for item in generate_data(2) {
-                tx4.send(item);
+                tx4.send(item).unwrap();
             }
         });
         scope.spawn(move || {
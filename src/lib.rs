@@ -1,37 +1,211 @@
 use atomic_wait::{wait, wake_all, wake_one};
 use crossbeam::queue::SegQueue;
+use std::future::Future;
 use std::ops::Deref;
+use std::pin::Pin;
 use std::sync::atomic::AtomicU32;
-use std::sync::atomic::Ordering::{Acquire, Relaxed, Release};
-use std::sync::Arc;
+use std::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, Release};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
 
 struct Counter {
     senders: AtomicU32,
     receivers: AtomicU32,
     waiting: AtomicU32,
+    // senders parked on `send_wait` waiting for a bounded channel to drain
+    senders_waiting: AtomicU32,
 }
 
 struct ChannelInner<T> {
     // u32 for threads to wait on
     wait: AtomicU32,
+    // u32 for blocked senders to wait on (bounded channels only)
+    send_wait: AtomicU32,
     // channel messages
     data: SegQueue<T>,
     // number of senders to keep track when channel is closed
     counter: Counter,
+    // maximum number of queued items; `None` is unbounded, `Some(0)` rendezvous
+    capacity: Option<usize>,
+    // slots currently reserved in `data`, kept in lock-step with the queue on
+    // capacity-bearing channels so the cap is enforced atomically: a sender
+    // claims a slot with `try_reserve` before pushing and a receiver releases
+    // it on `pop`. Always 0 (and unused) on unbounded channels.
+    len: AtomicU32,
+    // tokens of `Select`s currently parked on this channel, bumped and woken
+    // on every send so a selector blocked on several channels wakes on any
+    selectors: Mutex<Vec<Arc<AtomicU32>>>,
+    // fast-path guard so the send hot path only locks `selectors` when occupied
+    selector_count: AtomicU32,
+    // wakers of async tasks parked in `recv_async`
+    recv_wakers: Mutex<Vec<Waker>>,
+    recv_waker_count: AtomicU32,
+    // wakers of async tasks parked in `send_async` (bounded channels)
+    send_wakers: Mutex<Vec<Waker>>,
+    send_waker_count: AtomicU32,
+    // when set, this is a timer source rather than a data channel: `recv`
+    // parks until the next fire time instead of waiting on a sender
+    timer: Option<TimerState>,
+    // produces the message delivered on each fire (the current `Instant`)
+    producer: Option<Box<dyn Fn() -> T + Send + Sync>>,
+}
+
+// Schedule backing `after` / `tick` receivers.
+struct TimerState {
+    // instant of the next fire
+    next: Mutex<Instant>,
+    // `Some(d)` repeats every `d` (tick), `None` fires once (after)
+    period: Option<Duration>,
+    // set once a one-shot `after` timer has delivered its single message
+    fired: AtomicU32,
 }
 
 impl<T> ChannelInner<T> {
     fn new() -> Self {
+        Self::with_capacity(None)
+    }
+
+    fn with_capacity(capacity: Option<usize>) -> Self {
         Self {
             wait: AtomicU32::new(0),
+            send_wait: AtomicU32::new(0),
             data: SegQueue::new(),
             counter: Counter {
                 senders: AtomicU32::new(1),
                 receivers: AtomicU32::new(1),
                 waiting: AtomicU32::new(0),
+                senders_waiting: AtomicU32::new(0),
             },
+            capacity,
+            len: AtomicU32::new(0),
+            selectors: Mutex::new(Vec::new()),
+            selector_count: AtomicU32::new(0),
+            recv_wakers: Mutex::new(Vec::new()),
+            recv_waker_count: AtomicU32::new(0),
+            send_wakers: Mutex::new(Vec::new()),
+            send_waker_count: AtomicU32::new(0),
+            timer: None,
+            producer: None,
+        }
+    }
+
+    // wake the blocking receiver (and any selectors / async tasks) after an
+    // item is queued
+    fn notify_recv(&self) {
+        self.wait.fetch_add(1, Release);
+        if self.counter.waiting.load(Acquire) > 0 {
+            wake_one(&self.wait);
+        }
+        self.wake_selectors();
+        self.wake_recv_tasks();
+    }
+
+    // release bounded senders parked waiting for a free slot (threads and
+    // async tasks alike)
+    fn notify_send(&self) {
+        if self.capacity.is_some() && self.counter.senders_waiting.load(Acquire) > 0 {
+            self.send_wait.fetch_add(1, Release);
+            wake_all(&self.send_wait);
+        }
+        self.wake_send_tasks();
+    }
+
+    // Atomically claim one of `cap` slots, returning `false` when full. Used by
+    // every sending path so concurrent senders can never overshoot the cap the
+    // way a check-then-push would.
+    fn try_reserve(&self, cap: usize) -> bool {
+        let mut cur = self.len.load(Acquire);
+        loop {
+            if cur as usize >= cap {
+                return false;
+            }
+            match self
+                .len
+                .compare_exchange_weak(cur, cur + 1, AcqRel, Acquire)
+            {
+                Ok(_) => return true,
+                Err(actual) => cur = actual,
+            }
         }
     }
+
+    // Pop one item, releasing its reserved slot on capacity-bearing channels so
+    // a blocked sender can claim it. Unbounded channels never reserve, so their
+    // counter stays untouched.
+    fn pop(&self) -> Option<T> {
+        let item = self.data.pop()?;
+        if self.capacity.is_some() {
+            self.len.fetch_sub(1, AcqRel);
+        }
+        Some(item)
+    }
+
+    // drain and wake async receivers parked in `recv_async`
+    fn wake_recv_tasks(&self) {
+        if self.recv_waker_count.load(Acquire) == 0 {
+            return;
+        }
+        let mut wakers = self.recv_wakers.lock().unwrap();
+        self.recv_waker_count.store(0, Release);
+        for waker in wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    // drain and wake async senders parked in `send_async`
+    fn wake_send_tasks(&self) {
+        if self.send_waker_count.load(Acquire) == 0 {
+            return;
+        }
+        let mut wakers = self.send_wakers.lock().unwrap();
+        self.send_waker_count.store(0, Release);
+        for waker in wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    // register an async receiver's waker
+    fn register_recv_waker(&self, waker: &Waker) {
+        let mut wakers = self.recv_wakers.lock().unwrap();
+        wakers.push(waker.clone());
+        self.recv_waker_count.store(wakers.len() as u32, Release);
+    }
+
+    // register an async sender's waker
+    fn register_send_waker(&self, waker: &Waker) {
+        let mut wakers = self.send_wakers.lock().unwrap();
+        wakers.push(waker.clone());
+        self.send_waker_count.store(wakers.len() as u32, Release);
+    }
+
+    // wake every `Select` registered on this channel; cheap no-op when none
+    fn wake_selectors(&self) {
+        if self.selector_count.load(Acquire) == 0 {
+            return;
+        }
+        let selectors = self.selectors.lock().unwrap();
+        for token in selectors.iter() {
+            token.fetch_add(1, Release);
+            wake_one(Arc::as_ptr(token));
+        }
+    }
+}
+
+impl ChannelInner<Instant> {
+    // Build a timer source: the first message fires `first` from now, and
+    // `period` repeats (tick) or `None` fires just once (after). All the other
+    // fields reuse the plain unbounded layout.
+    fn timer(first: Duration, period: Option<Duration>) -> Self {
+        let mut inner = Self::with_capacity(None);
+        inner.timer = Some(TimerState {
+            next: Mutex::new(Instant::now() + first),
+            period,
+            fired: AtomicU32::new(0),
+        });
+        inner.producer = Some(Box::new(Instant::now));
+        inner
+    }
 }
 
 #[derive(Clone)]
@@ -45,6 +219,12 @@ impl<T> Channel<T> {
             inner: Arc::new(ChannelInner::new()),
         }
     }
+
+    fn bounded(cap: usize) -> Self {
+        Self {
+            inner: Arc::new(ChannelInner::with_capacity(Some(cap))),
+        }
+    }
 }
 
 impl<T> Deref for Channel<T> {
@@ -60,18 +240,142 @@ pub struct Sender<T> {
 }
 
 impl<T> Sender<T> {
-    pub fn send(&self, item: T) {
-        // add item to queue
-        self.inner.data.push(item);
-        // wake up waiting thread
-        self.inner.wait.fetch_add(1, Release);
-        if self.inner.counter.waiting.load(Acquire) > 0 {
-            wake_one(&self.inner.wait);
+    pub fn send(&self, item: T) -> Result<(), SendError<T>> {
+        match self.inner.capacity {
+            // rendezvous: hand the item over and wait until a receiver takes it
+            Some(0) => {
+                self.inner.counter.senders_waiting.fetch_add(1, Release);
+                // claim the single handoff slot, serialising concurrent senders
+                loop {
+                    if self.inner.counter.receivers.load(Acquire) == 0 {
+                        self.inner.counter.senders_waiting.fetch_sub(1, Release);
+                        return Err(SendError(item));
+                    }
+                    if self.inner.try_reserve(1) {
+                        break;
+                    }
+                    let snapshot = self.inner.send_wait.load(Relaxed);
+                    if self.inner.try_reserve(1) {
+                        break;
+                    }
+                    wait(&self.inner.send_wait, snapshot);
+                }
+                self.inner.data.push(item);
+                self.inner.notify_recv();
+                // block until the item is consumed (the slot is released)
+                loop {
+                    if self.inner.len.load(Acquire) == 0 {
+                        break;
+                    }
+                    if self.inner.counter.receivers.load(Acquire) == 0 {
+                        // the last receiver left before taking it: reclaim it
+                        self.inner.counter.senders_waiting.fetch_sub(1, Release);
+                        return match self.inner.pop() {
+                            Some(item) => Err(SendError(item)),
+                            None => Ok(()),
+                        };
+                    }
+                    let snapshot = self.inner.send_wait.load(Relaxed);
+                    if self.inner.len.load(Acquire) == 0 {
+                        break;
+                    }
+                    wait(&self.inner.send_wait, snapshot);
+                }
+                self.inner.counter.senders_waiting.fetch_sub(1, Release);
+                Ok(())
+            }
+            // bounded: block until a slot can be reserved
+            Some(cap) => {
+                self.inner.counter.senders_waiting.fetch_add(1, Release);
+                loop {
+                    if self.inner.counter.receivers.load(Acquire) == 0 {
+                        self.inner.counter.senders_waiting.fetch_sub(1, Release);
+                        return Err(SendError(item));
+                    }
+                    if self.inner.try_reserve(cap) {
+                        break;
+                    }
+                    let snapshot = self.inner.send_wait.load(Relaxed);
+                    if self.inner.try_reserve(cap) {
+                        break;
+                    }
+                    wait(&self.inner.send_wait, snapshot);
+                }
+                self.inner.counter.senders_waiting.fetch_sub(1, Release);
+                self.inner.data.push(item);
+                self.inner.notify_recv();
+                Ok(())
+            }
+            // unbounded: never blocks
+            None => {
+                if self.inner.counter.receivers.load(Acquire) == 0 {
+                    return Err(SendError(item));
+                }
+                self.inner.data.push(item);
+                self.inner.notify_recv();
+                Ok(())
+            }
+        }
+    }
+
+    /// Try to push without blocking, handing the item back if the channel is
+    /// full (or a rendezvous channel with no receiver parked) or disconnected.
+    pub fn try_send(&self, item: T) -> Result<(), TrySendError<T>> {
+        if self.inner.counter.receivers.load(Acquire) == 0 {
+            return Err(TrySendError::Disconnected(item));
+        }
+        match self.inner.capacity {
+            // rendezvous: succeeds only when a receiver is already parked to
+            // take the item straight away
+            Some(0) => {
+                if self.inner.counter.waiting.load(Acquire) > 0 && self.inner.try_reserve(1) {
+                    self.inner.data.push(item);
+                    self.inner.notify_recv();
+                    Ok(())
+                } else {
+                    Err(TrySendError::Full(item))
+                }
+            }
+            // bounded: reserve a slot atomically or report full
+            Some(cap) => {
+                if self.inner.try_reserve(cap) {
+                    self.inner.data.push(item);
+                    self.inner.notify_recv();
+                    Ok(())
+                } else {
+                    Err(TrySendError::Full(item))
+                }
+            }
+            // unbounded: always room
+            None => {
+                self.inner.data.push(item);
+                self.inner.notify_recv();
+                Ok(())
+            }
         }
     }
 
-    pub fn send_many(&self, items: Vec<T>) {
-        // add item to queue
+    pub fn send_many(&self, items: Vec<T>) -> Result<(), SendError<Vec<T>>> {
+        if self.inner.counter.receivers.load(Acquire) == 0 {
+            return Err(SendError(items));
+        }
+        // Bounded and rendezvous channels must apply back-pressure, so push the
+        // items one at a time through the blocking `send` path rather than
+        // dumping the whole batch past the capacity gate. Any items left when
+        // the channel closes are handed back.
+        if self.inner.capacity.is_some() {
+            let mut iter = items.into_iter();
+            loop {
+                let Some(item) = iter.next() else { break };
+                if let Err(SendError(item)) = self.send(item) {
+                    let mut rest = vec![item];
+                    rest.extend(iter);
+                    return Err(SendError(rest));
+                }
+            }
+            return Ok(());
+        }
+        // unbounded: the batch never blocks, so push it in one pass
         for item in items {
             self.inner.data.push(item);
         }
@@ -85,9 +389,36 @@ impl<T> Sender<T> {
                 wake_all(&self.inner.wait);
             }
         }
+        self.inner.wake_selectors();
+        self.inner.wake_recv_tasks();
+        Ok(())
+    }
+
+    /// Push an item, awaiting a free slot on a bounded channel instead of
+    /// blocking the thread. Resolves to [`SendError`] if the channel closes.
+    pub fn send_async(&self, item: T) -> SendFuture<'_, T> {
+        SendFuture {
+            tx: self,
+            item: Some(item),
+            pushed: false,
+        }
     }
 }
 
+/// Error returned by [`Sender::send`] / [`Sender::send_many`] when every
+/// receiver has been dropped; carries the value that could not be delivered.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+/// Error returned by [`Sender::try_send`] when the item could not be queued.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TrySendError<T> {
+    /// The channel is at capacity (or a rendezvous with no waiting receiver).
+    Full(T),
+    /// Every receiver has been dropped.
+    Disconnected(T),
+}
+
 impl<T> Clone for Sender<T> {
     fn clone(&self) -> Self {
         self.inner.counter.senders.fetch_add(1, Relaxed);
@@ -104,26 +435,54 @@ impl<T> Drop for Sender<T> {
             if self.inner.counter.waiting.load(Acquire) != 0 {
                 wake_all(&self.inner.wait);
             }
+            // closing is a readiness change too: let selectors observe it
+            self.inner.wake_selectors();
+            self.inner.wake_recv_tasks();
         }
     }
 }
 
-#[derive(Clone)]
 pub struct Receiver<T> {
     inner: Channel<T>,
 }
 
 impl<T> Receiver<T> {
+    /// Block until an item is available, returning `None` once the channel is
+    /// closed and drained.
+    ///
+    /// A thin wrapper around [`recv_blocking`] kept for source compatibility.
+    ///
+    /// [`recv_blocking`]: Receiver::recv_blocking
     pub fn recv(&self) -> Option<T> {
+        self.recv_blocking().ok()
+    }
+
+    /// Block until an item is available.
+    ///
+    /// Returns [`RecvError`] once the queue is drained and every sender has
+    /// dropped, so callers can tell a genuine disconnect from a value.
+    pub fn recv_blocking(&self) -> Result<T, RecvError> {
+        if self.inner.timer.is_some() {
+            loop {
+                if let Some(item) = self.timer_poll() {
+                    return Ok(item);
+                }
+                if self.timer_closed() {
+                    return Err(RecvError);
+                }
+                self.timer_park();
+            }
+        }
         loop {
             // there's an item in the queue
-            if let Some(item) = self.inner.data.pop() {
-                return Some(item);
+            if let Some(item) = self.inner.pop() {
+                self.inner.notify_send();
+                return Ok(item);
             }
             // channel is closed
             let num_senders = self.inner.counter.senders.load(Acquire);
             if num_senders == 0 {
-                return None;
+                return Err(RecvError);
             }
             // queue is empty
             self.inner.counter.waiting.fetch_add(1, Release);
@@ -132,17 +491,110 @@ impl<T> Receiver<T> {
         }
     }
 
+    /// Pop a single item without blocking.
+    ///
+    /// Distinguishes an empty-but-open channel ([`TryRecvError::Empty`]) from
+    /// one whose senders have all dropped ([`TryRecvError::Disconnected`]).
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        if self.inner.timer.is_some() {
+            return match self.timer_poll() {
+                Some(item) => Ok(item),
+                None if self.timer_closed() => Err(TryRecvError::Disconnected),
+                None => Err(TryRecvError::Empty),
+            };
+        }
+        if let Some(item) = self.inner.pop() {
+            self.inner.notify_send();
+            return Ok(item);
+        }
+        if self.inner.counter.senders.load(Acquire) == 0 {
+            Err(TryRecvError::Disconnected)
+        } else {
+            Err(TryRecvError::Empty)
+        }
+    }
+
+    /// Await the next item without tying up an OS thread, resolving to `None`
+    /// once the channel is closed and drained.
+    pub fn recv_async(&self) -> RecvFuture<'_, T> {
+        RecvFuture { rx: self }
+    }
+
+    /// A blocking iterator that yields items until the channel closes.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { rx: self }
+    }
+
+    /// A non-blocking iterator that yields currently-queued items and stops at
+    /// the first empty pop.
+    pub fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter { rx: self }
+    }
+
+    // non-blocking pop used by `select!` once the channel reports ready; yields
+    // `None` when the readiness was a close (or the item was stolen by a peer)
+    #[doc(hidden)]
+    pub fn __select_pop(&self) -> Option<T> {
+        if self.inner.timer.is_some() {
+            return self.timer_poll();
+        }
+        let item = self.inner.pop();
+        if item.is_some() {
+            self.inner.notify_send();
+        }
+        item
+    }
+
+    // If this is a timer channel and its next fire time has arrived, produce
+    // the message and advance (tick) or close (after) the schedule. Returns
+    // `None` on a data channel, a not-yet-due timer, or a spent one-shot.
+    fn timer_poll(&self) -> Option<T> {
+        let timer = self.inner.timer.as_ref()?;
+        if timer.period.is_none() && timer.fired.load(Acquire) != 0 {
+            return None;
+        }
+        let mut next = timer.next.lock().unwrap();
+        if Instant::now() < *next {
+            return None;
+        }
+        match timer.period {
+            Some(period) => *next += period,
+            None => timer.fired.store(1, Release),
+        }
+        drop(next);
+        Some((self.inner.producer.as_ref().unwrap())())
+    }
+
+    // A one-shot `after` timer reports closed once it has delivered.
+    fn timer_closed(&self) -> bool {
+        matches!(&self.inner.timer, Some(t) if t.period.is_none() && t.fired.load(Acquire) != 0)
+    }
+
+    // Park until the next fire time. A timer has no sender to race with, so we
+    // can sleep straight to the deadline; a spurious early wake just re-checks.
+    fn timer_park(&self) {
+        if let Some(timer) = self.inner.timer.as_ref() {
+            let next = *timer.next.lock().unwrap();
+            let now = Instant::now();
+            if now < next {
+                std::thread::park_timeout(next - now);
+            }
+        }
+    }
+
     pub fn recv_exact(&self, num: usize) -> Option<Vec<T>> {
         loop {
             // there's an item in the queue
             let mut data = Vec::with_capacity(num);
             loop {
-                if let Some(item) = self.inner.data.pop() {
+                if let Some(item) = self.inner.pop() {
                     data.push(item);
                     if data.len() == num {
+                        self.inner.notify_send();
                         return Some(data);
                     }
                 } else if !data.is_empty() {
+                    self.inner.notify_send();
                     return Some(data);
                 } else {
                     break;
@@ -164,9 +616,10 @@ impl<T> Receiver<T> {
             // there's an item in the queue
             let mut data = Vec::new();
             loop {
-                if let Some(item) = self.inner.data.pop() {
+                if let Some(item) = self.inner.pop() {
                     data.push(item);
                 } else if !data.is_empty() {
+                    self.inner.notify_send();
                     return Some(data);
                 } else {
                     break;
@@ -182,6 +635,529 @@ impl<T> Receiver<T> {
             self.inner.counter.waiting.fetch_sub(1, Release);
         }
     }
+
+    /// Block for at most `timeout`, then give up.
+    ///
+    /// Returns [`RecvTimeoutError::Timeout`] if no item arrives in time and
+    /// [`RecvTimeoutError::Disconnected`] once the queue is drained and every
+    /// sender has dropped.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.recv_deadline(Instant::now() + timeout)
+    }
+
+    /// Block until `deadline`, then give up. See [`recv_timeout`].
+    ///
+    /// [`recv_timeout`]: Receiver::recv_timeout
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        if self.inner.timer.is_some() {
+            loop {
+                if let Some(item) = self.timer_poll() {
+                    return Ok(item);
+                }
+                if self.timer_closed() {
+                    return Err(RecvTimeoutError::Disconnected);
+                }
+                if park_slice(deadline).is_none() {
+                    return Err(RecvTimeoutError::Timeout);
+                }
+            }
+        }
+        loop {
+            if let Some(item) = self.inner.pop() {
+                self.inner.notify_send();
+                return Ok(item);
+            }
+            if self.inner.counter.senders.load(Acquire) == 0 {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            // `atomic_wait::wait` has no timeout, so poll in bounded slices and
+            // re-check the queue and the closed flag on every wake.
+            if park_slice(deadline).is_none() {
+                return Err(RecvTimeoutError::Timeout);
+            }
+        }
+    }
+
+    /// Like [`recv_exact`] but bounded by `timeout`; returns whatever was
+    /// collected so far when the deadline is reached.
+    ///
+    /// [`recv_exact`]: Receiver::recv_exact
+    pub fn recv_exact_timeout(
+        &self,
+        num: usize,
+        timeout: Duration,
+    ) -> Result<Vec<T>, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut data = Vec::with_capacity(num);
+        loop {
+            while let Some(item) = self.inner.pop() {
+                data.push(item);
+                if data.len() == num {
+                    self.inner.notify_send();
+                    return Ok(data);
+                }
+            }
+            if self.inner.counter.senders.load(Acquire) == 0 {
+                return if data.is_empty() {
+                    Err(RecvTimeoutError::Disconnected)
+                } else {
+                    Ok(data)
+                };
+            }
+            if park_slice(deadline).is_none() {
+                return finish_partial(data);
+            }
+        }
+    }
+
+    /// Like [`recv_all`] but bounded by `timeout`: returns everything queued as
+    /// soon as at least one item is available, or [`RecvTimeoutError::Timeout`]
+    /// if nothing arrives before the deadline.
+    ///
+    /// [`recv_all`]: Receiver::recv_all
+    pub fn recv_all_timeout(&self, timeout: Duration) -> Result<Vec<T>, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut data = Vec::new();
+        loop {
+            while let Some(item) = self.inner.pop() {
+                data.push(item);
+            }
+            if !data.is_empty() {
+                self.inner.notify_send();
+                return Ok(data);
+            }
+            if self.inner.counter.senders.load(Acquire) == 0 {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            if park_slice(deadline).is_none() {
+                return Err(RecvTimeoutError::Timeout);
+            }
+        }
+    }
+}
+
+/// Error returned by the `recv_timeout` / `recv_deadline` family.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    /// No item became available before the deadline.
+    Timeout,
+    /// The queue is empty and every sender has dropped.
+    Disconnected,
+}
+
+// Park for up to one polling slice, never past `deadline`. Returns `Some(())`
+// if the deadline has not yet passed (caller should re-check the queue) and
+// `None` once it has.
+fn park_slice(deadline: Instant) -> Option<()> {
+    let now = Instant::now();
+    if now >= deadline {
+        return None;
+    }
+    let slice = (deadline - now).min(Duration::from_millis(1));
+    std::thread::park_timeout(slice);
+    Some(())
+}
+
+// Hand back a partial batch on timeout, or `Timeout` if nothing arrived.
+fn finish_partial<T>(data: Vec<T>) -> Result<Vec<T>, RecvTimeoutError> {
+    if data.is_empty() {
+        Err(RecvTimeoutError::Timeout)
+    } else {
+        Ok(data)
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.inner.counter.receivers.fetch_add(1, Relaxed);
+        let channel = Channel {
+            inner: self.inner.clone(),
+        };
+        Self { inner: channel }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        if self.inner.counter.receivers.fetch_sub(1, Release) == 1 {
+            // no receiver left: release any parked senders so they observe the
+            // disconnect instead of blocking forever
+            self.inner.send_wait.fetch_add(1, Release);
+            wake_all(&self.inner.send_wait);
+            self.inner.wake_send_tasks();
+        }
+    }
+}
+
+/// Error returned by [`Receiver::recv_blocking`] when the channel is empty and
+/// every sender has dropped.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RecvError;
+
+/// Error returned by [`Receiver::try_recv`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// The channel is empty but still has live senders.
+    Empty,
+    /// The channel is empty and every sender has dropped.
+    Disconnected,
+}
+
+/// A blocking iterator over a [`Receiver`], created by [`Receiver::iter`].
+pub struct Iter<'a, T> {
+    rx: &'a Receiver<T>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.recv()
+    }
+}
+
+/// A non-blocking iterator over a [`Receiver`], created by
+/// [`Receiver::try_iter`].
+pub struct TryIter<'a, T> {
+    rx: &'a Receiver<T>,
+}
+
+impl<T> Iterator for TryIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.try_recv().ok()
+    }
+}
+
+/// An owning blocking iterator over a [`Receiver`], created by its
+/// [`IntoIterator`] impl.
+pub struct IntoIter<T> {
+    rx: Receiver<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.recv()
+    }
+}
+
+impl<T> IntoIterator for Receiver<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { rx: self }
+    }
+}
+
+/// Future returned by [`Receiver::recv_async`].
+pub struct RecvFuture<'a, T> {
+    rx: &'a Receiver<T>,
+}
+
+impl<T> Future for RecvFuture<'_, T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let inner = &self.rx.inner;
+        if let Some(item) = inner.pop() {
+            inner.notify_send();
+            return Poll::Ready(Some(item));
+        }
+        if inner.counter.senders.load(Acquire) == 0 {
+            return Poll::Ready(None);
+        }
+        // Register first, then re-check: a send landing between the failed pop
+        // above and registration would otherwise be lost.
+        inner.register_recv_waker(cx.waker());
+        if let Some(item) = inner.pop() {
+            inner.notify_send();
+            return Poll::Ready(Some(item));
+        }
+        if inner.counter.senders.load(Acquire) == 0 {
+            return Poll::Ready(None);
+        }
+        Poll::Pending
+    }
+}
+
+/// Future returned by [`Sender::send_async`].
+pub struct SendFuture<'a, T> {
+    tx: &'a Sender<T>,
+    item: Option<T>,
+    // set once the item has been pushed but not yet taken, so a rendezvous send
+    // keeps awaiting consumption across polls rather than completing early
+    pushed: bool,
+}
+
+// `SendFuture` holds only references and an `Option<T>`, never a self-borrow,
+// so it is safe to move while polled.
+impl<T> Unpin for SendFuture<'_, T> {}
+
+impl<T> Future for SendFuture<'_, T> {
+    type Output = Result<(), SendError<T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError<T>>> {
+        let this = self.get_mut();
+        let inner = &this.tx.inner;
+
+        // A rendezvous send that has already handed its item off is only waiting
+        // for a receiver to take it: complete once the slot is released.
+        if this.pushed {
+            if inner.len.load(Acquire) == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            if inner.counter.receivers.load(Acquire) == 0 {
+                return match inner.pop() {
+                    Some(item) => Poll::Ready(Err(SendError(item))),
+                    None => Poll::Ready(Ok(())),
+                };
+            }
+            inner.register_send_waker(cx.waker());
+            if inner.len.load(Acquire) == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            return Poll::Pending;
+        }
+
+        if inner.counter.receivers.load(Acquire) == 0 {
+            let item = this.item.take().expect("polled after completion");
+            return Poll::Ready(Err(SendError(item)));
+        }
+        // The cap to reserve against: unbounded never gates, rendezvous holds a
+        // single in-flight slot, bounded uses its declared capacity.
+        match inner.capacity {
+            None => {
+                let item = this.item.take().expect("polled after completion");
+                inner.data.push(item);
+                inner.notify_recv();
+                Poll::Ready(Ok(()))
+            }
+            Some(cap) => {
+                let reserve = if cap == 0 { 1 } else { cap };
+                if !this.try_push(reserve) {
+                    inner.register_send_waker(cx.waker());
+                    // Re-reserve after registering to avoid missing a drain.
+                    if !this.try_push(reserve) {
+                        return Poll::Pending;
+                    }
+                }
+                // A bounded send completes once pushed; a rendezvous send must
+                // still wait for the item to be taken (slot released).
+                if cap != 0 {
+                    return Poll::Ready(Ok(()));
+                }
+                if inner.len.load(Acquire) == 0 {
+                    return Poll::Ready(Ok(()));
+                }
+                inner.register_send_waker(cx.waker());
+                if inner.len.load(Acquire) == 0 {
+                    return Poll::Ready(Ok(()));
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> SendFuture<'_, T> {
+    // Reserve a slot and, on success, push the pending item. Returns whether the
+    // push happened.
+    fn try_push(&mut self, reserve: usize) -> bool {
+        let inner = &self.tx.inner;
+        if !inner.try_reserve(reserve) {
+            return false;
+        }
+        let item = self.item.take().expect("polled after completion");
+        inner.data.push(item);
+        inner.notify_recv();
+        self.pushed = true;
+        true
+    }
+}
+
+/// A channel end that can participate in a [`Select`] / [`select!`].
+///
+/// The trait is object-safe on purpose: a `Select` stores its receivers as
+/// `&dyn Selectable` so that channels carrying different element types can be
+/// waited on together. None of the methods touch the element type.
+pub trait Selectable {
+    /// `true` when a non-blocking receive would make progress — either an item
+    /// is queued or the channel has closed.
+    fn is_ready(&self) -> bool;
+    /// Register `token` so that a send (or close) on this channel wakes it.
+    fn register(&self, token: &Arc<AtomicU32>);
+    /// Remove a previously registered `token`.
+    fn deregister(&self, token: &Arc<AtomicU32>);
+    /// The next instant this channel will become ready on its own, if any.
+    ///
+    /// Timer sources (`after` / `tick`) have no sender to bump the token, so a
+    /// [`Select`] parking on them instead wakes at this deadline. Data channels
+    /// return `None`.
+    fn deadline(&self) -> Option<Instant>;
+}
+
+impl<T> Selectable for Receiver<T> {
+    fn is_ready(&self) -> bool {
+        if let Some(timer) = self.inner.timer.as_ref() {
+            if timer.period.is_none() && timer.fired.load(Acquire) != 0 {
+                return true; // spent one-shot: closed, hence ready
+            }
+            return Instant::now() >= *timer.next.lock().unwrap();
+        }
+        !self.inner.data.is_empty() || self.inner.counter.senders.load(Acquire) == 0
+    }
+
+    fn register(&self, token: &Arc<AtomicU32>) {
+        let mut selectors = self.inner.selectors.lock().unwrap();
+        selectors.push(token.clone());
+        self.inner.selector_count.store(selectors.len() as u32, Release);
+    }
+
+    fn deregister(&self, token: &Arc<AtomicU32>) {
+        let mut selectors = self.inner.selectors.lock().unwrap();
+        if let Some(pos) = selectors.iter().position(|t| Arc::ptr_eq(t, token)) {
+            selectors.swap_remove(pos);
+        }
+        self.inner.selector_count.store(selectors.len() as u32, Release);
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        let timer = self.inner.timer.as_ref()?;
+        if timer.period.is_none() && timer.fired.load(Acquire) != 0 {
+            return None;
+        }
+        Some(*timer.next.lock().unwrap())
+    }
+}
+
+/// Waits for one of several channels to become ready.
+///
+/// `Select` registers its own [`AtomicU32`] token on every channel it holds;
+/// each sender bumps and wakes that token, so a thread parked here wakes as
+/// soon as *any* registered channel receives an item (or closes). Most callers
+/// reach for the [`select!`] macro instead of driving `Select` by hand.
+pub struct Select<'a> {
+    token: Arc<AtomicU32>,
+    channels: Vec<&'a dyn Selectable>,
+}
+
+impl<'a> Select<'a> {
+    pub fn new() -> Self {
+        Self {
+            token: Arc::new(AtomicU32::new(0)),
+            channels: Vec::new(),
+        }
+    }
+
+    /// Add a receiver, returning the index used to identify it in [`ready`].
+    ///
+    /// [`ready`]: Select::ready
+    pub fn add(&mut self, channel: &'a dyn Selectable) -> usize {
+        self.channels.push(channel);
+        self.channels.len() - 1
+    }
+
+    /// Scan every channel once without blocking, returning the index of the
+    /// first ready one.
+    pub fn try_ready(&self) -> Option<usize> {
+        self.channels.iter().position(|c| c.is_ready())
+    }
+
+    // The soonest timer fire time across the registered channels, if any.
+    fn earliest_deadline(&self) -> Option<Instant> {
+        self.channels.iter().filter_map(|c| c.deadline()).min()
+    }
+
+    /// Block until at least one channel is ready and return its index.
+    pub fn ready(&self) -> usize {
+        loop {
+            if let Some(index) = self.try_ready() {
+                return index;
+            }
+            // Register before the final re-scan so a send landing between the
+            // scan and the park bumps the token and makes `wait` return at once.
+            for channel in &self.channels {
+                channel.register(&self.token);
+            }
+            let snapshot = self.token.load(Relaxed);
+            let ready = self.try_ready();
+            if ready.is_none() {
+                // A timer has no sender to bump the token, so park in bounded
+                // slices up to the soonest fire time and re-scan; otherwise
+                // block on the futex until a send wakes us.
+                match self.earliest_deadline() {
+                    Some(deadline) => {
+                        park_slice(deadline);
+                    }
+                    None => wait(&self.token, snapshot),
+                }
+            }
+            for channel in &self.channels {
+                channel.deregister(&self.token);
+            }
+            if let Some(index) = ready {
+                return index;
+            }
+        }
+    }
+}
+
+impl<'a> Default for Select<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wait on several channels at once, acting on whichever is ready first.
+///
+/// ```ignore
+/// select! {
+///     recv(rx1) -> msg => println!("from a: {:?}", msg),
+///     recv(rx2) -> msg => println!("from b: {:?}", msg),
+///     default => println!("nothing ready"),
+/// }
+/// ```
+///
+/// Each `msg` is bound to the `Option<T>` that [`Receiver::recv`] would return:
+/// `Some(item)` for a delivered value, `None` when that channel is closed (or
+/// when another receiver took the item between the readiness scan and the
+/// pop). With
+/// a `default` arm the macro never blocks; without one it parks until a channel
+/// is ready.
+#[macro_export]
+macro_rules! select {
+    // internal: dispatch the chosen index to the matching arm's body
+    (@dispatch $idx:expr, $cur:expr,) => {
+        unreachable!("select! index out of range")
+    };
+    (@dispatch $idx:expr, $cur:expr, recv($rx:expr) -> $msg:pat => $body:expr, $($rest:tt)*) => {
+        if $idx == $cur {
+            let $msg = $rx.__select_pop();
+            $body
+        } else {
+            $crate::select!(@dispatch $idx, $cur + 1usize, $($rest)*)
+        }
+    };
+    // blocking form
+    ($(recv($rx:expr) -> $msg:pat => $body:expr),+ $(,)?) => {{
+        let mut __sel = $crate::Select::new();
+        $( __sel.add(&$rx); )+
+        let __idx = __sel.ready();
+        $crate::select!(@dispatch __idx, 0usize, $(recv($rx) -> $msg => $body,)+)
+    }};
+    // non-blocking form with a default arm
+    ($(recv($rx:expr) -> $msg:pat => $body:expr),+ , default => $default:expr $(,)?) => {{
+        let mut __sel = $crate::Select::new();
+        $( __sel.add(&$rx); )+
+        match __sel.try_ready() {
+            Some(__idx) => $crate::select!(@dispatch __idx, 0usize, $(recv($rx) -> $msg => $body,)+),
+            None => $default,
+        }
+    }};
 }
 
 unsafe impl<T> Sync for Sender<T> {}
@@ -201,6 +1177,49 @@ pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
     (sender, recv)
 }
 
+/// Create a bounded channel holding at most `cap` items before `send` blocks.
+///
+/// `bounded(0)` is a rendezvous channel: every `send` blocks until a receiver
+/// takes the item.
+pub fn bounded<T>(cap: usize) -> (Sender<T>, Receiver<T>) {
+    let channel = Channel::bounded(cap);
+    let sender = Sender {
+        inner: Channel {
+            inner: channel.inner.clone(),
+        },
+    };
+    let recv = Receiver { inner: channel };
+    (sender, recv)
+}
+
+/// A receiver that yields the current [`Instant`] once, after `duration` has
+/// elapsed, and then reports closed.
+///
+/// Works with `recv`, `recv_blocking`, `try_recv`, `recv_timeout`,
+/// `recv_deadline`, the iterators and [`select!`], so a loop can fold a timeout
+/// into the same `recv` path as its data channels. The batch receives
+/// (`recv_exact`, `recv_all` and their `_timeout` variants) and `recv_async`
+/// do not observe timer fires: they wait on the empty queue instead.
+pub fn after(duration: Duration) -> Receiver<Instant> {
+    Receiver {
+        inner: Channel {
+            inner: Arc::new(ChannelInner::timer(duration, None)),
+        },
+    }
+}
+
+/// A receiver that yields the current [`Instant`] every `duration`, forever.
+///
+/// Like [`after`] it plugs into [`select!`], but it never reports closed and
+/// re-arms its deadline after each delivery.
+pub fn tick(duration: Duration) -> Receiver<Instant> {
+    Receiver {
+        inner: Channel {
+            inner: Arc::new(ChannelInner::timer(duration, Some(duration))),
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,14 +1228,14 @@ mod tests {
     #[test]
     fn it_works() {
         let (tx, rx) = channel();
-        tx.send(101);
+        tx.send(101).unwrap();
         assert_eq!(rx.recv().unwrap(), 101);
     }
 
     #[test]
     fn channel_close() {
         let (tx, rx) = channel();
-        tx.send(101);
+        tx.send(101).unwrap();
         assert_eq!(rx.recv().unwrap(), 101);
         drop(tx);
         assert!(rx.recv().is_none());
@@ -227,7 +1246,7 @@ mod tests {
         let (tx, rx) = channel();
         std::thread::spawn(move || {
             std::thread::sleep(Duration::from_micros(100));
-            tx.send(101);
+            tx.send(101).unwrap();
         });
         assert_eq!(rx.recv().unwrap(), 101);
         assert!(rx.recv().is_none());
@@ -237,7 +1256,7 @@ mod tests {
     fn recv_exact() {
         let data = (0..8).collect::<Vec<u16>>();
         let (tx, rx) = channel();
-        tx.send_many(data);
+        tx.send_many(data).unwrap();
         drop(tx);
         assert_eq!(rx.recv_exact(5).unwrap().len(), 5);
         assert_eq!(rx.recv_exact(5).unwrap().len(), 3);
@@ -248,9 +1267,279 @@ mod tests {
     fn recv_all() {
         let data = (0..8).collect::<Vec<u16>>();
         let (tx, rx) = channel();
-        tx.send_many(data);
+        tx.send_many(data).unwrap();
         drop(tx);
         assert_eq!(rx.recv_all().unwrap().len(), 8);
         assert!(rx.recv_all().is_none());
     }
+
+    #[test]
+    fn select_picks_ready_channel() {
+        let (tx1, rx1) = channel::<u8>();
+        let (_tx2, rx2) = channel::<String>();
+        tx1.send(7).unwrap();
+        let got = select! {
+            recv(rx1) -> msg => msg.map(|v| v as i32),
+            recv(rx2) -> msg => msg.map(|s| s.len() as i32),
+        };
+        assert_eq!(got, Some(7));
+    }
+
+    #[test]
+    fn select_default_when_empty() {
+        let (_tx1, rx1) = channel::<u8>();
+        let (_tx2, rx2) = channel::<u8>();
+        let got = select! {
+            recv(rx1) -> _msg => 1,
+            recv(rx2) -> _msg => 2,
+            default => 0,
+        };
+        assert_eq!(got, 0);
+    }
+
+    #[test]
+    fn recv_async_ready_and_pending() {
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        let (tx, rx) = channel();
+        let mut cx = Context::from_waker(Waker::noop());
+        // empty channel parks the task
+        assert!(matches!(
+            pin!(rx.recv_async()).as_mut().poll(&mut cx),
+            Poll::Pending
+        ));
+        tx.send(5).unwrap();
+        assert_eq!(
+            pin!(rx.recv_async()).as_mut().poll(&mut cx),
+            Poll::Ready(Some(5))
+        );
+    }
+
+    #[test]
+    fn iter_blocks_until_closed() {
+        let (tx, rx) = channel();
+        tx.send_many(vec![1, 2, 3]).unwrap();
+        drop(tx);
+        assert_eq!(rx.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn try_iter_drains_queued() {
+        let (tx, rx) = channel();
+        tx.send_many(vec![1, 2, 3]).unwrap();
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        // nothing left, but the channel is still open
+        assert_eq!(rx.try_iter().next(), None);
+    }
+
+    #[test]
+    fn into_iter_consumes_receiver() {
+        let (tx, rx) = channel();
+        tx.send_many(vec![1, 2, 3]).unwrap();
+        drop(tx);
+        assert_eq!(rx.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn try_recv_distinguishes_empty_and_closed() {
+        let (tx, rx) = channel::<u8>();
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+        drop(tx);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
+    }
+
+    #[test]
+    fn send_to_closed_returns_item() {
+        let (tx, rx) = channel();
+        drop(rx);
+        assert_eq!(tx.send(7), Err(SendError(7)));
+    }
+
+    #[test]
+    fn recv_timeout_times_out() {
+        let (_tx, rx) = channel::<u8>();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(5)),
+            Err(RecvTimeoutError::Timeout)
+        );
+    }
+
+    #[test]
+    fn recv_timeout_gets_item() {
+        let (tx, rx) = channel();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_micros(100));
+            tx.send(99).unwrap();
+        });
+        assert_eq!(rx.recv_timeout(Duration::from_secs(1)), Ok(99));
+    }
+
+    #[test]
+    fn recv_timeout_disconnected() {
+        let (tx, rx) = channel::<u8>();
+        drop(tx);
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(1)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+    }
+
+    #[test]
+    fn recv_all_timeout_returns_partial() {
+        let (tx, rx) = channel();
+        tx.send_many(vec![1, 2, 3]).unwrap();
+        assert_eq!(rx.recv_all_timeout(Duration::from_secs(1)), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            rx.recv_all_timeout(Duration::from_millis(5)),
+            Err(RecvTimeoutError::Timeout)
+        );
+    }
+
+    #[test]
+    fn bounded_try_send_full() {
+        let (tx, _rx) = bounded(2);
+        assert!(tx.try_send(1).is_ok());
+        assert!(tx.try_send(2).is_ok());
+        assert_eq!(tx.try_send(3), Err(TrySendError::Full(3)));
+    }
+
+    #[test]
+    fn bounded_cap_is_hard_under_concurrency() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        let (tx, _rx) = bounded(4);
+        let tx = Arc::new(tx);
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let mut handles = Vec::new();
+        for _ in 0..8 {
+            let tx = tx.clone();
+            let accepted = accepted.clone();
+            handles.push(std::thread::spawn(move || {
+                for v in 0..100u32 {
+                    if tx.try_send(v).is_ok() {
+                        accepted.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }));
+        }
+        for h in handles {
+            h.join().unwrap();
+        }
+        // nobody drains, so the atomic slot reservation caps accepted items at
+        // exactly the capacity no matter how the senders race
+        assert_eq!(accepted.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn rendezvous_try_send_with_parked_receiver() {
+        let (tx, rx) = bounded(0);
+        let handle = std::thread::spawn(move || rx.recv());
+        // let the receiver park in recv() so try_send can hand off to it
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(tx.try_send(7).is_ok());
+        assert_eq!(handle.join().unwrap(), Some(7));
+    }
+
+    #[test]
+    fn send_async_rendezvous_pending_until_received() {
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        let (tx, rx) = bounded(0);
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut fut = pin!(tx.send_async(7));
+        // the item is handed off but no receiver has taken it yet
+        assert_eq!(fut.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(rx.recv(), Some(7));
+        // once consumed, the send completes
+        assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn bounded_send_blocks_until_drained() {
+        let (tx, rx) = bounded(1);
+        tx.send(1).unwrap();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_micros(100));
+            assert_eq!(rx.recv().unwrap(), 1);
+            assert_eq!(rx.recv().unwrap(), 2);
+        });
+        // blocks until the spawned thread drains the first item
+        tx.send(2).unwrap();
+    }
+
+    #[test]
+    fn send_many_respects_bounded_capacity() {
+        let (tx, rx) = bounded(2);
+        std::thread::spawn(move || {
+            // four items into a cap-2 channel: the extra two must wait for the
+            // receiver to drain rather than overflowing the queue
+            tx.send_many(vec![1, 2, 3, 4]).unwrap();
+        });
+        let mut got = Vec::new();
+        while got.len() < 4 {
+            got.push(rx.recv().unwrap());
+        }
+        assert_eq!(got, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn rendezvous_waits_for_receiver() {
+        let (tx, rx) = bounded(0);
+        let handle = std::thread::spawn(move || {
+            tx.send(101).unwrap();
+        });
+        assert_eq!(rx.recv().unwrap(), 101);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn select_wakes_on_late_send() {
+        let (tx1, rx1) = channel::<u8>();
+        let (_tx2, rx2) = channel::<u8>();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_micros(100));
+            tx1.send(42).unwrap();
+        });
+        let got = select! {
+            recv(rx1) -> msg => msg,
+            recv(rx2) -> msg => msg,
+        };
+        assert_eq!(got, Some(42));
+    }
+
+    #[test]
+    fn after_fires_once_then_closes() {
+        let start = Instant::now();
+        let rx = after(Duration::from_millis(10));
+        let fired = rx.recv().unwrap();
+        assert!(fired.duration_since(start) >= Duration::from_millis(10));
+        // a one-shot closes after its single delivery
+        assert!(rx.recv().is_none());
+    }
+
+    #[test]
+    fn tick_repeats() {
+        let start = Instant::now();
+        let rx = tick(Duration::from_millis(5));
+        let first = rx.recv().unwrap();
+        let second = rx.recv().unwrap();
+        // deliveries are monotonic and spaced from the construction instant, so
+        // the second tick cannot arrive before two whole periods have elapsed
+        assert!(second > first);
+        assert!(second.duration_since(start) >= Duration::from_millis(10));
+    }
+
+    #[test]
+    fn select_multiplexes_data_and_timeout() {
+        let (_tx, rx) = channel::<u8>();
+        let timeout = after(Duration::from_millis(10));
+        // no data arrives, so the timer arm wins
+        let got = select! {
+            recv(rx) -> msg => msg.map(|v| v as i64),
+            recv(timeout) -> _deadline => Some(-1i64),
+        };
+        assert_eq!(got, Some(-1));
+    }
 }